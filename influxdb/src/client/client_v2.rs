@@ -15,6 +15,10 @@
 //! assert_eq!(client.database_name(), "test");
 //! ```
 use reqwest::{Client as ReqwestClient, StatusCode, header::{HeaderMap, HeaderValue}};
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as BlockingReqwestClient;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
 
 use crate::query::QueryTypes;
 use crate::Error;
@@ -22,12 +26,366 @@ use crate::Query;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// A Flux query, sent to the native InfluxDB 2.x `/api/v2/query` endpoint.
+///
+/// This is the Flux equivalent of [`ReadQuery`](crate::ReadQuery): where
+/// `ReadQuery` speaks the v1-compatible `InfluxQL` dialect over `/query`,
+/// `FluxQuery` always POSTs Flux text to `/api/v2/query` and gets back
+/// InfluxDB 2.x *annotated CSV* instead of JSON.
+///
+/// # Examples
+///
+/// ```rust
+/// use influxdb::FluxQuery;
+///
+/// let query = FluxQuery::new(r#"from(bucket: "test") |> range(start: -1h)"#);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FluxQuery {
+  query: String,
+}
+
+impl FluxQuery {
+  /// Creates a new [`FluxQuery`]
+  pub fn new<S>(query: S) -> Self
+    where
+      S: Into<String>,
+  {
+    FluxQuery { query: query.into() }
+  }
+
+  /// Returns the raw Flux text that will be sent as the request body
+  pub fn get(&self) -> &str {
+    &self.query
+  }
+
+  /// Builds a [`FluxQuery`] from a template containing the
+  /// [`RANGE_PLACEHOLDER`] (`$range`), expanding it into a Flux
+  /// `range(start: ..., stop: ...)` call.
+  ///
+  /// `start` and `stop` each accept either an RFC3339 datetime or a
+  /// relative duration such as `-1h` (the same convention used by the
+  /// `range()` function itself). `stop` may be omitted, in which case it
+  /// defaults to "now" as Flux's `range()` does.
+  ///
+  /// # Arguments
+  ///
+  ///  * `template`: Flux text containing the `$range` placeholder
+  ///  * `start`: the range's lower bound, e.g. `-1h` or `2021-01-01T00:00:00Z`
+  ///  * `stop`: the range's upper bound, if any
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use influxdb::FluxQuery;
+  ///
+  /// let query = FluxQuery::with_range(
+  ///   r#"from(bucket: "test") |> $range |> filter(fn: (r) => r._measurement == "cpu")"#,
+  ///   "-1h",
+  ///   None,
+  /// ).unwrap();
+  ///
+  /// assert_eq!(
+  ///   query.get(),
+  ///   r#"from(bucket: "test") |> range(start: -1h) |> filter(fn: (r) => r._measurement == "cpu")"#
+  /// );
+  /// ```
+  /// # Errors
+  ///
+  /// Returns an [`Error::InvalidQueryError`] if `template` does not contain
+  /// the `$range` placeholder, or if `start`/`stop` are neither a valid
+  /// RFC3339 datetime nor a relative duration.
+  pub fn with_range<S>(template: S, start: &str, stop: Option<&str>) -> Result<Self, Error>
+    where
+      S: Into<String>,
+  {
+    let template = template.into();
+    if !template.contains(RANGE_PLACEHOLDER) {
+      return Err(Error::InvalidQueryError {
+        error: format!("template does not contain the `{}` placeholder", RANGE_PLACEHOLDER),
+      });
+    }
+
+    validate_range_bound(start)?;
+    if let Some(stop) = stop {
+      validate_range_bound(stop)?;
+    }
+
+    let range = match stop {
+      Some(stop) => format!("range(start: {}, stop: {})", start, stop),
+      None => format!("range(start: {})", start),
+    };
+
+    Ok(FluxQuery { query: template.replace(RANGE_PLACEHOLDER, &range) })
+  }
+}
+
+/// Placeholder substituted by [`FluxQuery::with_range`] with a Flux
+/// `range(start:, stop:)` call.
+pub const RANGE_PLACEHOLDER: &str = "$range";
+
+/// Returns `true` if `bound` is a valid Flux relative duration, e.g. `-1h`,
+/// `30m`, `7d`.
+fn is_relative_duration(bound: &str) -> bool {
+  let unsigned = bound.strip_prefix('-').unwrap_or(bound);
+  let digits_end = unsigned.find(|c: char| !c.is_ascii_digit()).unwrap_or(unsigned.len());
+
+  if digits_end == 0 {
+    return false;
+  }
+
+  matches!(
+    &unsigned[digits_end..],
+    "ns" | "us" | "µs" | "ms" | "s" | "m" | "h" | "d" | "w" | "mo" | "y"
+  )
+}
+
+/// Validates that `bound` is either an RFC3339 datetime or a relative
+/// duration, as accepted by Flux's `range()` function.
+fn validate_range_bound(bound: &str) -> Result<(), Error> {
+  if bound.parse::<chrono::DateTime<chrono::Utc>>().is_ok() || is_relative_duration(bound) {
+    return Ok(());
+  }
+
+  Err(Error::InvalidQueryError {
+    error: format!("`{}` is neither an RFC3339 datetime nor a relative duration", bound),
+  })
+}
+
+/// A single table parsed out of an InfluxDB 2.x annotated CSV response.
+///
+/// InfluxDB 2.x may return several tables in one response, each preceded by
+/// its own `#datatype`/`#group`/`#default` annotation block and header row,
+/// separated from the previous table by a blank line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FluxTable {
+  /// Column names, taken from the header row
+  pub columns: Vec<String>,
+  /// The `#datatype` annotation for each column, e.g. `double`, `long`,
+  /// `string`, `boolean`, `dateTime:RFC3339`
+  pub datatypes: Vec<String>,
+  /// Data rows, in column order
+  pub rows: Vec<Vec<String>>,
+}
+
+/// Splits a raw InfluxDB 2.x annotated CSV response into [`FluxTable`]s.
+///
+/// A response can contain multiple result sets, each separated by a blank
+/// line and each carrying its own annotation block. This only splits rows
+/// into columns; it does not coerce values according to `#datatype`.
+fn parse_annotated_csv(raw: &str) -> Vec<FluxTable> {
+  let mut tables = Vec::new();
+  let mut datatypes: Vec<String> = Vec::new();
+  let mut columns: Vec<String> = Vec::new();
+  let mut rows: Vec<Vec<String>> = Vec::new();
+
+  for line in raw.lines() {
+    let line = line.trim_end_matches('\r');
+
+    if line.trim().is_empty() {
+      if !columns.is_empty() {
+        tables.push(FluxTable {
+          columns: std::mem::take(&mut columns),
+          datatypes: std::mem::take(&mut datatypes),
+          rows: std::mem::take(&mut rows),
+        });
+      }
+      continue;
+    }
+
+    let fields = split_csv_line(line);
+
+    if line.starts_with("#datatype") {
+      datatypes = fields.into_iter().skip(1).collect();
+    } else if line.starts_with("#group") || line.starts_with("#default") {
+      // annotation consumed, but not needed to build a FluxTable
+    } else if columns.is_empty() {
+      // the leading field is the annotation marker column, empty on header/data rows
+      columns = fields.into_iter().skip(1).collect();
+    } else {
+      rows.push(fields.into_iter().skip(1).collect());
+    }
+  }
+
+  if !columns.is_empty() {
+    tables.push(FluxTable { columns, datatypes, rows });
+  }
+
+  tables
+}
+
+/// Builds the `Authorization` header and `org`/`bucket` query parameters
+/// shared by [`ClientV2::new`] and [`ClientV2Builder::build`].
+fn build_headers_and_parameters(token: &str, org: String, bucket: String) -> (HeaderMap<HeaderValue>, HashMap<&'static str, String>) {
+  let mut headers = HeaderMap::new();
+  let mut parameters = HashMap::<&str, String>::new();
+  parameters.insert("org", org);
+  parameters.insert("bucket", bucket);
+  headers.insert("Authorization", HeaderValue::from_str(&format!("Token {}", token)).unwrap());
+
+  (headers, parameters)
+}
+
+/// Gzip-compresses a line-protocol write body, for use with
+/// [`ClientV2::with_gzip`].
+fn gzip_compress(body: &str) -> Result<Vec<u8>, Error> {
+  use std::io::Write;
+
+  let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+  encoder.write_all(body.as_bytes()).map_err(|err| Error::ProtocolError {
+    error: err.to_string(),
+  })?;
+
+  encoder.finish().map_err(|err| Error::ProtocolError {
+    error: err.to_string(),
+  })
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+  let mut reader = csv::ReaderBuilder::new()
+    .has_headers(false)
+    .from_reader(line.as_bytes());
+
+  match reader.records().next() {
+    Some(Ok(record)) => record.iter().map(|field| field.to_string()).collect(),
+    _ => Vec::new(),
+  }
+}
+
+/// Coerces a raw annotated-CSV cell into a [`serde_json::Value`] according
+/// to its `#datatype` annotation, so that it can later be deserialized into
+/// a user-provided `T` via `serde_json`.
+fn coerce_annotated_value(datatype: &str, raw: &str) -> Value {
+  if raw.is_empty() {
+    return Value::Null;
+  }
+
+  match datatype {
+    "double" => raw.parse::<f64>().map(Value::from).unwrap_or(Value::Null),
+    "long" => raw.parse::<i64>().map(Value::from).unwrap_or(Value::Null),
+    "unsignedLong" => raw.parse::<u64>().map(Value::from).unwrap_or(Value::Null),
+    "boolean" => raw.parse::<bool>().map(Value::from).unwrap_or(Value::Null),
+    // dateTime:RFC3339 (and dateTime:RFC3339Nano) are left as strings;
+    // `chrono::DateTime<Utc>` deserializes directly from an RFC3339 string.
+    _ => Value::String(raw.to_string()),
+  }
+}
+
+/// Deserializes a single [`FluxTable`] row into a `T`, coercing each cell
+/// according to the table's `#datatype` annotations.
+fn deserialize_flux_row<T: DeserializeOwned>(table: &FluxTable, row: &[String]) -> Result<T, Error> {
+  if table.columns.len() != table.datatypes.len() {
+    return Err(Error::DeserializationError {
+      error: format!(
+        "table has {} column(s) but {} #datatype entries",
+        table.columns.len(),
+        table.datatypes.len()
+      ),
+    });
+  }
+
+  let mut map = Map::new();
+  for ((column, datatype), raw) in table.columns.iter().zip(table.datatypes.iter()).zip(row.iter()) {
+    map.insert(column.clone(), coerce_annotated_value(datatype, raw));
+  }
+
+  serde_json::from_value(Value::Object(map)).map_err(|err| Error::DeserializationError {
+    error: err.to_string(),
+  })
+}
+
 #[derive(Clone, Debug)]
 /// Internal Representation of a Client
 pub struct ClientV2 {
   pub(crate) url: Arc<String>,
   pub(crate) headers: Arc<HeaderMap<HeaderValue>>,
   pub(crate) parameters: Arc<HashMap<&'static str, String>>,
+  pub(crate) client: Arc<ReqwestClient>,
+  pub(crate) gzip: bool,
+}
+
+/// Builds a [`ClientV2`] with custom `reqwest` settings (request timeout,
+/// connection pool size, TLS backend) instead of the defaults `ClientV2::new`
+/// uses.
+///
+/// # Examples
+///
+/// ```rust
+/// use influxdb::ClientV2;
+/// use std::time::Duration;
+///
+/// let client = ClientV2::builder("http://localhost:8086", "YOURAUTHTOKEN", "org", "bucket")
+///   .timeout(Duration::from_secs(10))
+///   .pool_max_idle_per_host(8)
+///   .build()
+///   .unwrap();
+/// ```
+pub struct ClientV2Builder {
+  url: String,
+  token: String,
+  org: String,
+  bucket: String,
+  timeout: Option<std::time::Duration>,
+  pool_max_idle_per_host: Option<usize>,
+  use_rustls_tls: bool,
+  gzip: bool,
+}
+
+impl ClientV2Builder {
+  /// Sets the timeout applied to every request made with the built client
+  pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+    self.timeout = Some(timeout);
+    self
+  }
+
+  /// Sets the maximum number of idle connections per host kept in the pool
+  pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+    self.pool_max_idle_per_host = Some(max);
+    self
+  }
+
+  /// Uses `rustls` instead of the platform's native TLS implementation
+  pub fn use_rustls_tls(mut self) -> Self {
+    self.use_rustls_tls = true;
+    self
+  }
+
+  /// Gzip-compresses line-protocol write bodies before sending them to
+  /// `/api/v2/write`. See [`ClientV2::with_gzip`] for details.
+  pub fn gzip(mut self) -> Self {
+    self.gzip = true;
+    self
+  }
+
+  /// Builds the [`ClientV2`], constructing the underlying `reqwest::Client`
+  /// once so it can be reused (and its connection pool kept warm) across
+  /// every request made with this client.
+  pub fn build(self) -> Result<ClientV2, Error> {
+    let mut builder = ReqwestClient::builder();
+    if let Some(timeout) = self.timeout {
+      builder = builder.timeout(timeout);
+    }
+    if let Some(max) = self.pool_max_idle_per_host {
+      builder = builder.pool_max_idle_per_host(max);
+    }
+    if self.use_rustls_tls {
+      builder = builder.use_rustls_tls();
+    }
+
+    let client = builder.build().map_err(|err| Error::ProtocolError {
+      error: err.to_string(),
+    })?;
+
+    let (headers, parameters) = build_headers_and_parameters(&self.token, self.org, self.bucket);
+
+    Ok(ClientV2 {
+      url: Arc::new(self.url),
+      headers: Arc::new(headers),
+      parameters: Arc::new(parameters),
+      client: Arc::new(client),
+      gzip: self.gzip,
+    })
+  }
 }
 
 impl ClientV2 {
@@ -51,18 +409,60 @@ impl ClientV2 {
         S2: Into<String>,
         S3: Into<String>,
     {
-      let mut headers = HeaderMap::new();
-      let mut parameters = HashMap::<&str, String>::new();
-      parameters.insert("org", org.into());
-      parameters.insert("bucket", bucket.into());
-      headers.insert("Authorization", HeaderValue::from_str(&format!("Token {}", token)).unwrap());
+      let (headers, parameters) = build_headers_and_parameters(token, org.into(), bucket.into());
       ClientV2 {
         url: Arc::new(url.into()),
         headers: Arc::new(headers),
-        parameters: Arc::new(parameters)
+        parameters: Arc::new(parameters),
+        client: Arc::new(ReqwestClient::new()),
+        gzip: false,
+      }
+    }
+
+    /// Starts building a [`ClientV2`] with custom `reqwest` settings, such as
+    /// request timeout, connection pool size, or TLS backend.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use influxdb::ClientV2;
+    ///
+    /// let _client = ClientV2::builder("http://localhost:8086", "YOURAUTHTOKEN", "org", "bucket").build();
+    /// ```
+    pub fn builder<S1, S2, S3>(url: S1, token: &str, org: S2, bucket: S3) -> ClientV2Builder
+      where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+      ClientV2Builder {
+        url: url.into(),
+        token: token.to_owned(),
+        org: org.into(),
+        bucket: bucket.into(),
+        timeout: None,
+        pool_max_idle_per_host: None,
+        use_rustls_tls: false,
+        gzip: false,
       }
     }
 
+    /// Gzip-compresses line-protocol write bodies before sending them to
+    /// `/api/v2/write`, and sets the `Content-Encoding: gzip` header
+    /// accordingly. Reads are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use influxdb::ClientV2;
+    ///
+    /// let _client = ClientV2::new("http://localhost:8086", "YOURAUTHTOKEN", "org", "bucket").with_gzip();
+    /// ```
+    pub fn with_gzip(mut self) -> Self {
+      self.gzip = true;
+      self
+    }
+
     /// Returns the name of the bucket the client is using
     pub fn token(&self) -> &str {
       self.get_header_by_name("Authorization")
@@ -87,7 +487,7 @@ impl ClientV2 {
     /// Returns a tuple of build type and version number
     pub async fn ping(&self) -> Result<(String, String), Error> {
       let url = &format!("{}/ping", self.url);
-      let client = ReqwestClient::new();
+      let client = &self.client;
       let res = client
         .get(url)
         .send()
@@ -146,7 +546,7 @@ impl ClientV2 {
         Q: Query,
         &'q Q: Into<QueryTypes<'q>>,
     {
-      let client = ReqwestClient::new();
+      let client = &self.client;
       let query = q.build().map_err(|err| Error::InvalidQueryError {
         error: err.to_string(),
       })?;
@@ -166,11 +566,18 @@ impl ClientV2 {
         }
         QueryTypes::Write(write_query) => {
           let url = &format!("{}/api/v2/write", &self.url);
-          let headers = self.headers.as_ref().clone();
+          let mut headers = self.headers.as_ref().clone();
           let mut parameters = self.parameters.as_ref().clone();
           parameters.insert("precision", write_query.get_precision());
 
-          client.post(url).headers(headers).body(query.get()).query(&parameters)
+          let body = if self.gzip {
+            headers.insert("Content-Encoding", HeaderValue::from_static("gzip"));
+            gzip_compress(query.get())?
+          } else {
+            query.get().as_bytes().to_vec()
+          };
+
+          client.post(url).headers(headers).body(body).query(&parameters)
         }
       }.build();
 
@@ -184,8 +591,6 @@ impl ClientV2 {
           error: err.to_string(),
         })?;
 
-
-
       match res.status() {
         StatusCode::UNAUTHORIZED => return Err(Error::AuthorizationError),
         StatusCode::FORBIDDEN => return Err(Error::AuthenticationError),
@@ -208,6 +613,363 @@ impl ClientV2 {
 
       Ok(s)
     }
+
+    /// Sends a [`FluxQuery`] to the InfluxDB Server's native `/api/v2/query` endpoint.
+    ///
+    /// Unlike [`query`](ClientV2::query), which returns the raw response
+    /// string, this parses the InfluxDB 2.x annotated CSV response into
+    /// structured [`FluxTable`]s. A version capable of deserializing rows
+    /// directly into user types is available under
+    /// [`query_flux_as`](ClientV2::query_flux_as).
+    ///
+    /// # Arguments
+    ///
+    ///  * `q`: The [`FluxQuery`] to run
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use influxdb::{ClientV2, FluxQuery};
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() -> Result<(), influxdb::Error> {
+    /// let client = ClientV2::new("http://localhost:8086", "YOURAUTHTOKEN", "org", "bucket");
+    /// let query = FluxQuery::new(r#"from(bucket: "bucket") |> range(start: -1h)"#);
+    /// let tables = client.query_flux(&query).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// If the function can not finish the query,
+    /// a [`Error`] variant will be returned.
+    ///
+    /// [`Error`]: enum.Error.html
+    pub async fn query_flux(&self, q: &FluxQuery) -> Result<Vec<FluxTable>, Error> {
+      let client = &self.client;
+      let url = &format!("{}/api/v2/query", &self.url);
+
+      let mut headers = self.headers.as_ref().clone();
+      headers.insert("Content-Type", HeaderValue::from_static("application/vnd.flux"));
+      headers.insert("Accept", HeaderValue::from_static("application/csv"));
+
+      let mut parameters = self.parameters.as_ref().clone();
+      parameters.remove("bucket");
+
+      let request = client
+        .post(url)
+        .headers(headers)
+        .query(&parameters)
+        .body(q.get().to_owned())
+        .build()
+        .map_err(|err| Error::UrlConstructionError {
+          error: err.to_string(),
+        })?;
+
+      let res = client
+        .execute(request)
+        .await
+        .map_err(|err| Error::ConnectionError {
+          error: err.to_string(),
+        })?;
+
+      match res.status() {
+        StatusCode::UNAUTHORIZED => return Err(Error::AuthorizationError),
+        StatusCode::FORBIDDEN => return Err(Error::AuthenticationError),
+        _ => {}
+      }
+
+      let s = res
+        .text()
+        .await
+        .map_err(|_| Error::DeserializationError {
+          error: "response could not be converted to UTF-8".to_string(),
+        })?;
+
+      if s.contains("\"error\"") {
+        return Err(Error::DatabaseError {
+          error: format!("influxdb error: \"{}\"", s),
+        });
+      }
+
+      Ok(parse_annotated_csv(&s))
+    }
+
+    /// Sends a [`FluxQuery`] and deserializes each returned row into `T`.
+    ///
+    /// Rather than handing back the string cells of the [`FluxTable`]s from
+    /// [`query_flux`](ClientV2::query_flux), each row is coerced according
+    /// to its `#datatype` annotation (RFC3339 timestamps, `long`, `double`,
+    /// `boolean`) and deserialized directly into a user-provided `T`. A
+    /// response that concatenates multiple
+    /// tables yields one inner `Vec<T>` per table.
+    ///
+    /// # Arguments
+    ///
+    ///  * `q`: The [`FluxQuery`] to run
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use influxdb::{ClientV2, FluxQuery};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Temperature {
+    ///     #[serde(rename = "_value")]
+    ///     value: f64,
+    /// }
+    ///
+    /// # #[async_std::main]
+    /// # async fn main() -> Result<(), influxdb::Error> {
+    /// let client = ClientV2::new("http://localhost:8086", "YOURAUTHTOKEN", "org", "bucket");
+    /// let query = FluxQuery::new(r#"from(bucket: "bucket") |> range(start: -1h)"#);
+    /// let tables: Vec<Vec<Temperature>> = client.query_flux_as(&query).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// If the function can not finish the query, or a row can not be
+    /// deserialized into `T`, a [`Error`] variant will be returned.
+    ///
+    /// [`Error`]: enum.Error.html
+    pub async fn query_flux_as<T: DeserializeOwned>(&self, q: &FluxQuery) -> Result<Vec<Vec<T>>, Error> {
+      let tables = self.query_flux(q).await?;
+
+      tables
+        .iter()
+        .map(|table| {
+          table
+            .rows
+            .iter()
+            .map(|row| deserialize_flux_row(table, row))
+            .collect::<Result<Vec<T>, Error>>()
+        })
+        .collect()
+    }
+}
+
+/// A blocking counterpart to [`ClientV2`], built on `reqwest::blocking`.
+///
+/// This exists so `ClientV2` can be used from synchronous call sites — most
+/// notably connection-pool abstractions like [`r2d2`] — without dragging an
+/// async runtime along for the ride. It mirrors [`ClientV2::new`],
+/// [`ClientV2::ping`] and [`ClientV2::query`], reusing the same
+/// endpoint-routing and error-mapping rules.
+///
+/// Only available with the `blocking` feature enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use influxdb::SyncClientV2;
+///
+/// let client = SyncClientV2::new("http://localhost:8086", "YOURAUTHTOKEN", "org", "bucket");
+/// ```
+#[cfg(feature = "blocking")]
+#[derive(Clone, Debug)]
+pub struct SyncClientV2 {
+  pub(crate) url: Arc<String>,
+  pub(crate) headers: Arc<HeaderMap<HeaderValue>>,
+  pub(crate) parameters: Arc<HashMap<&'static str, String>>,
+  pub(crate) client: Arc<BlockingReqwestClient>,
+}
+
+#[cfg(feature = "blocking")]
+impl SyncClientV2 {
+  /// Instantiates a new [`SyncClientV2`](crate::SyncClientV2)
+  ///
+  /// # Arguments
+  ///
+  ///  * `url`: The URL where InfluxDB is running (ex. `http://localhost:8086`).
+  ///  * `org`: The Organization against which queries and writes will be run.
+  ///  * `bucket`: The Bucket against which queries and writes will be run.
+  pub fn new<S1, S2, S3>(url: S1, token: &str, org: S2, bucket: S3) -> Self
+    where
+      S1: Into<String>,
+      S2: Into<String>,
+      S3: Into<String>,
+  {
+    let mut headers = HeaderMap::new();
+    let mut parameters = HashMap::<&str, String>::new();
+    parameters.insert("org", org.into());
+    parameters.insert("bucket", bucket.into());
+    headers.insert("Authorization", HeaderValue::from_str(&format!("Token {}", token)).unwrap());
+    SyncClientV2 {
+      url: Arc::new(url.into()),
+      headers: Arc::new(headers),
+      parameters: Arc::new(parameters),
+      client: Arc::new(BlockingReqwestClient::new()),
+    }
+  }
+
+  /// Returns the token the client is using, as sent in the `Authorization` header
+  pub fn token(&self) -> &str {
+    if let Ok(value) = self.headers.get("Authorization").unwrap().to_str() {
+      return value;
+    }
+
+    ""
+  }
+
+  /// Returns the URL of the InfluxDB installation the client is using
+  pub fn database_url(&self) -> &str {
+    &self.url
+  }
+
+  /// Pings the InfluxDB Server
+  ///
+  /// Returns a tuple of build type and version number
+  pub fn ping(&self) -> Result<(String, String), Error> {
+    let url = &format!("{}/ping", self.url);
+    let client = &self.client;
+    let res = client
+      .get(url)
+      .send()
+      .map_err(|err| Error::ProtocolError {
+        error: format!("{}", err),
+      })?;
+    let headers = res.headers();
+
+    let build = headers["X-Influxdb-Build"].to_str().unwrap();
+    let version = headers["X-Influxdb-Version"].to_str().unwrap();
+
+    Ok((build.to_owned(), version.to_owned()))
+  }
+
+  /// Sends a [`ReadQuery`](crate::ReadQuery) or [`WriteQuery`](crate::WriteQuery) to the InfluxDB Server.
+  ///
+  /// See [`ClientV2::query`](crate::ClientV2::query) for the async equivalent
+  /// and full documentation of the endpoint-routing behavior.
+  ///
+  /// # Errors
+  ///
+  /// If the function can not finish the query,
+  /// a [`Error`] variant will be returned.
+  pub fn query<'q, Q>(&self, q: &'q Q) -> Result<String, Error>
+    where
+      Q: Query,
+      &'q Q: Into<QueryTypes<'q>>,
+  {
+    let client = &self.client;
+    let query = q.build().map_err(|err| Error::InvalidQueryError {
+      error: err.to_string(),
+    })?;
+
+    let request_builder = match q.into() {
+      QueryTypes::Read(_) => {
+        let read_query = query.get();
+        let headers = self.headers.as_ref().clone();
+        let parameters = self.parameters.as_ref().clone();
+        let url = &format!("{}/query", &self.url);
+
+        if read_query.contains("SELECT") || read_query.contains("SHOW") {
+          client.get(url).headers(headers).query(&parameters)
+        } else {
+          client.post(url).headers(headers).query(&parameters)
+        }
+      }
+      QueryTypes::Write(write_query) => {
+        let url = &format!("{}/api/v2/write", &self.url);
+        let headers = self.headers.as_ref().clone();
+        let mut parameters = self.parameters.as_ref().clone();
+        parameters.insert("precision", write_query.get_precision());
+
+        client.post(url).headers(headers).body(query.get()).query(&parameters)
+      }
+    }.build();
+
+    let request = request_builder.map_err(|err| Error::UrlConstructionError {
+      error: err.to_string(),
+    })?;
+    let res = client
+      .execute(request)
+      .map_err(|err| Error::ConnectionError {
+        error: err.to_string(),
+      })?;
+
+    match res.status() {
+      StatusCode::UNAUTHORIZED => return Err(Error::AuthorizationError),
+      StatusCode::FORBIDDEN => return Err(Error::AuthenticationError),
+      _ => {}
+    }
+
+    let s = res
+      .text()
+      .map_err(|_| Error::DeserializationError {
+        error: "response could not be converted to UTF-8".to_string(),
+      })?;
+
+    if s.contains("\"error\"") {
+      return Err(Error::DatabaseError {
+        error: format!("influxdb error: \"{}\"", s),
+      });
+    }
+
+    Ok(s)
+  }
+}
+
+/// An [`r2d2::ManageConnection`] implementation for [`SyncClientV2`].
+///
+/// This lets [`SyncClientV2`] be used directly as the connection type of an
+/// `r2d2::Pool`, so synchronous, thread-pooled callers get connection
+/// pooling the same way they would for any other `r2d2`-managed resource.
+///
+/// Only available with the `blocking` feature enabled.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use influxdb::ConnectionManager;
+///
+/// let manager = ConnectionManager::new("http://localhost:8086", "YOURAUTHTOKEN", "org", "bucket");
+/// let pool = r2d2::Pool::builder().build(manager).unwrap();
+/// ```
+#[cfg(feature = "blocking")]
+#[derive(Clone, Debug)]
+pub struct ConnectionManager {
+  url: String,
+  token: String,
+  org: String,
+  bucket: String,
+}
+
+#[cfg(feature = "blocking")]
+impl ConnectionManager {
+  /// Instantiates a new [`ConnectionManager`](crate::ConnectionManager)
+  pub fn new<S1, S2, S3>(url: S1, token: &str, org: S2, bucket: S3) -> Self
+    where
+      S1: Into<String>,
+      S2: Into<String>,
+      S3: Into<String>,
+  {
+    ConnectionManager {
+      url: url.into(),
+      token: token.to_owned(),
+      org: org.into(),
+      bucket: bucket.into(),
+    }
+  }
+}
+
+#[cfg(feature = "blocking")]
+impl r2d2::ManageConnection for ConnectionManager {
+  type Connection = SyncClientV2;
+  type Error = Error;
+
+  fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    Ok(SyncClientV2::new(&self.url, &self.token, &self.org, &self.bucket))
+  }
+
+  fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+    conn.ping().map(|_| ())
+  }
+
+  fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+    false
+  }
 }
 
 #[cfg(test)]
@@ -223,4 +985,126 @@ mod tests {
     assert_eq!(parameters.get("org").unwrap(), "org");
     assert_eq!(parameters.get("bucket").unwrap(), "bucket");
   }
+
+  use super::{coerce_annotated_value, deserialize_flux_row, gzip_compress, parse_annotated_csv, FluxQuery};
+  use serde_json::Value;
+
+  #[test]
+  fn test_flux_query_with_range() {
+    let query = FluxQuery::with_range(
+      r#"from(bucket: "test") |> $range"#,
+      "-1h",
+      None,
+    ).unwrap();
+    assert_eq!(query.get(), r#"from(bucket: "test") |> range(start: -1h)"#);
+
+    let query = FluxQuery::with_range(
+      r#"from(bucket: "test") |> $range"#,
+      "2021-01-01T00:00:00Z",
+      Some("2021-01-02T00:00:00Z"),
+    ).unwrap();
+    assert_eq!(
+      query.get(),
+      r#"from(bucket: "test") |> range(start: 2021-01-01T00:00:00Z, stop: 2021-01-02T00:00:00Z)"#
+    );
+  }
+
+  #[test]
+  fn test_flux_query_with_range_requires_placeholder() {
+    let result = FluxQuery::with_range(r#"from(bucket: "test")"#, "-1h", None);
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn test_flux_query_with_range_validates_bounds() {
+    let result = FluxQuery::with_range(r#"from(bucket: "test") |> $range"#, "not-a-time", None);
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn test_gzip_compress_round_trips() {
+    use std::io::Read;
+
+    let compressed = gzip_compress("cpu,host=server01 value=0.64").unwrap();
+    let mut decoder = flate2::read::GzDecoder::new(&compressed[..]);
+    let mut decompressed = String::new();
+    decoder.read_to_string(&mut decompressed).unwrap();
+
+    assert_eq!(decompressed, "cpu,host=server01 value=0.64");
+  }
+
+  #[cfg(feature = "blocking")]
+  #[test]
+  fn test_fn_sync_client_database() {
+    use super::SyncClientV2;
+
+    let client = SyncClientV2::new("http://localhost:8068", "YOURAUTHTOKEN", "org", "bucket");
+    assert_eq!(client.token(), "Token YOURAUTHTOKEN");
+    let parameters = client.parameters;
+    assert_eq!(parameters.len(), 2);
+    assert_eq!(parameters.get("org").unwrap(), "org");
+    assert_eq!(parameters.get("bucket").unwrap(), "bucket");
+  }
+
+  #[test]
+  fn test_parse_annotated_csv_single_table() {
+    let raw = "#datatype,string,long,double,string,dateTime:RFC3339\n\
+               #group,false,false,false,false,false\n\
+               #default,_result,,,,\n\
+               ,result,table,_value,_field,_time\n\
+               ,_result,0,82,temperature,2021-01-01T00:00:00Z\n";
+
+    let tables = parse_annotated_csv(raw);
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].columns, vec!["result", "table", "_value", "_field", "_time"]);
+    assert_eq!(tables[0].datatypes, vec!["string", "long", "double", "string", "dateTime:RFC3339"]);
+    assert_eq!(tables[0].rows.len(), 1);
+  }
+
+  #[test]
+  fn test_parse_annotated_csv_multiple_tables() {
+    let raw = "#datatype,string,long,double\n\
+               #group,false,false,false\n\
+               #default,_result,,\n\
+               ,result,table,_value\n\
+               ,_result,0,1\n\
+               \n\
+               #datatype,string,long,double\n\
+               #group,false,false,false\n\
+               #default,_result,,\n\
+               ,result,table,_value\n\
+               ,_result,1,2\n";
+
+    let tables = parse_annotated_csv(raw);
+    assert_eq!(tables.len(), 2);
+    assert_eq!(tables[0].rows, vec![vec!["_result".to_string(), "0".to_string(), "1".to_string()]]);
+    assert_eq!(tables[1].rows, vec![vec!["_result".to_string(), "1".to_string(), "2".to_string()]]);
+  }
+
+  #[derive(serde::Deserialize, Debug, PartialEq)]
+  struct TestReading {
+    #[serde(rename = "_value")]
+    value: f64,
+    #[serde(rename = "_field")]
+    field: String,
+  }
+
+  #[test]
+  fn test_deserialize_flux_row() {
+    let raw = "#datatype,string,long,double,string,dateTime:RFC3339\n\
+               #group,false,false,false,false,false\n\
+               #default,_result,,,,\n\
+               ,result,table,_value,_field,_time\n\
+               ,_result,0,82.5,temperature,2021-01-01T00:00:00Z\n";
+
+    let tables = parse_annotated_csv(raw);
+    let reading: TestReading = deserialize_flux_row(&tables[0], &tables[0].rows[0]).unwrap();
+    assert_eq!(reading, TestReading { value: 82.5, field: "temperature".to_string() });
+  }
+
+  #[test]
+  fn test_coerce_annotated_value_unsigned_long_above_i64_max() {
+    let value = coerce_annotated_value("unsignedLong", "18446744073709551615");
+    assert_eq!(value, Value::from(18446744073709551615u64));
+  }
 }
\ No newline at end of file